@@ -206,6 +206,7 @@ pub struct Field {
     pub guard: Option<TokenStream>,
     pub post_guard: Option<TokenStream>,
     pub features: Vec<String>,
+    pub map_err: Option<TokenStream>,
 }
 
 impl Field {
@@ -221,6 +222,7 @@ impl Field {
         let mut owned = false;
         let mut guard = None;
         let mut post_guard = None;
+        let mut map_err = None;
 
         for attr in attrs {
             match attr.parse_meta()? {
@@ -304,6 +306,16 @@ impl Field {
                                             "Attribute 'feature' should be a string.",
                                         ));
                                     }
+                                } else if nv.path.is_ident("map_err") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        let tokens: TokenStream = lit.value().parse()?;
+                                        map_err = Some(quote! { (#tokens) });
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'map_err' should be a string.",
+                                        ));
+                                    }
                                 }
                             }
                             NestedMeta::Meta(Meta::List(ls)) => {
@@ -335,6 +347,7 @@ impl Field {
             guard,
             post_guard,
             features,
+            map_err,
         }))
     }
 }
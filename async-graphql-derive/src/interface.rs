@@ -190,7 +190,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
 
         for enum_name in &enum_names {
             calls.push(quote! {
-                #ident::#enum_name(obj) => obj.#method_name(#(#use_params),*).await
+                #ident::#enum_name(obj) => obj.#method_name(#(#use_params),*).await.map_err(#crate_name::FieldError::from)
             });
         }
 
@@ -177,7 +177,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     )
                     .expect("invalid result type");
                 }
-                let do_find = quote! { self.#field_ident(ctx, #(#use_keys),*).await.map_err(|err| err.into_error(ctx.position()))? };
+                let do_find = quote! { self.#field_ident(ctx, #(#use_keys),*).await.map_err(|err| #crate_name::FieldError::from(err).into_error(ctx.position()))? };
 
                 find_entities.push((
                     args.len(),
@@ -375,6 +375,13 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
 
                 let field_ident = &method.sig.ident;
                 if let OutputType::Value(inner_ty) = &ty {
+                    if field.map_err.is_some() {
+                        return Err(Error::new_spanned(
+                            &method.sig.output,
+                            "Attribute 'map_err' requires the field to return a result.",
+                        ));
+                    }
+
                     let block = &method.block;
                     let new_block = quote!({
                         {
@@ -387,6 +394,26 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         quote! { -> #crate_name::FieldResult<#inner_ty> },
                     )
                     .expect("invalid result type");
+                } else if let OutputType::Result(result_ty, inner_ty) = &ty {
+                    // Convert the error in the method itself, so that `map_err` also takes effect
+                    // when the field is resolved through an interface, and the feature block can
+                    // return a `FieldError` whatever the original error type is.
+                    let err = match &field.map_err {
+                        Some(map_err) => quote! { #map_err(err) },
+                        None => quote! { err },
+                    };
+                    let block = &method.block;
+                    let new_block = quote!({
+                        {
+                            let res:#result_ty = async move #block.await;
+                            res.map_err(|err| #crate_name::FieldError::from(#err))
+                        }
+                    });
+                    method.block = syn::parse2::<Block>(new_block).expect("invalid block");
+                    method.sig.output = syn::parse2::<ReturnType>(
+                        quote! { -> #crate_name::FieldResult<#inner_ty> },
+                    )
+                    .expect("invalid result type");
                 }
 
                 method.block =
@@ -399,7 +426,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 let resolve_obj = quote! {
                     {
                         let res = self.#field_ident(ctx, #(#use_params),*).await;
-                        res.map_err(|err| err.into_error_with_path(ctx.position(), ctx.path_node.as_ref()))?
+                        res.map_err(|err| #crate_name::FieldError::from(err).into_error_with_path(ctx.position(), ctx.path_node.as_ref()))?
                     }
                 };
 
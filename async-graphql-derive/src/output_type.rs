@@ -10,13 +10,27 @@ pub enum OutputType<'a> {
 impl<'a> OutputType<'a> {
     pub fn parse(input: &'a Type) -> Result<Self> {
         let ty = if let Type::Path(p) = input {
-            if p.path.segments.last().unwrap().ident == "FieldResult" {
+            let ident = &p.path.segments.last().unwrap().ident;
+            if ident == "FieldResult" || ident == "Result" {
                 if let PathArguments::AngleBracketed(args) =
                     &p.path.segments.last().unwrap().arguments
                 {
                     if args.args.is_empty() {
                         return Err(Error::new_spanned(input, "Invalid type"));
                     }
+                    if ident == "Result"
+                        && args
+                            .args
+                            .iter()
+                            .filter(|arg| matches!(arg, GenericArgument::Type(_)))
+                            .count()
+                            != 2
+                    {
+                        return Err(Error::new_spanned(
+                            input,
+                            "The error type of `Result` must be specified explicitly, such as `Result<T, E>`, or use `FieldResult<T>`.",
+                        ));
+                    }
                     let mut res = None;
                     for arg in &args.args {
                         if let GenericArgument::Type(value_ty) = arg {
@@ -50,6 +64,29 @@ impl<'a> OutputType<'a> {
         ty
     }
 
+    /// Returns the result type with the value type replaced by `_`, so that it can annotate a
+    /// result whose value type is `impl Trait`.
+    pub fn result_type_with_inferred_value(&self) -> Option<Type> {
+        let mut ty = match self {
+            OutputType::Value(_) => return None,
+            OutputType::Result(ty, _) => (*ty).clone(),
+        };
+        if let Type::Path(p) = &mut ty {
+            if let PathArguments::AngleBracketed(args) =
+                &mut p.path.segments.last_mut().unwrap().arguments
+            {
+                if let Some(arg) = args
+                    .args
+                    .iter_mut()
+                    .find(|arg| matches!(arg, GenericArgument::Type(_)))
+                {
+                    *arg = GenericArgument::Type(syn::parse2(quote! { _ }).unwrap());
+                }
+            }
+        }
+        Some(ty)
+    }
+
     fn remove_lifecycle(ty: &mut Type) {
         match ty {
             Type::Reference(r) => {
@@ -193,6 +193,13 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 };
 
                 if let OutputType::Value(inner_ty) = &ty {
+                    if field.map_err.is_some() {
+                        return Err(Error::new_spanned(
+                            &method.sig.output,
+                            "Attribute 'map_err' requires the field to return a result.",
+                        ));
+                    }
+
                     let block = &method.block;
                     let new_block = quote!({
                         {
@@ -205,6 +212,24 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         quote! { -> #crate_name::FieldResult<#inner_ty> },
                     )
                     .expect("invalid result type");
+                } else if let OutputType::Result(_, inner_ty) = &ty {
+                    let result_ty = ty.result_type_with_inferred_value().unwrap();
+                    let err = match &field.map_err {
+                        Some(map_err) => quote! { #map_err(err) },
+                        None => quote! { err },
+                    };
+                    let block = &method.block;
+                    let new_block = quote!({
+                        {
+                            let res:#result_ty = (move || { async move #block })().await;
+                            res.map_err(|err| #crate_name::FieldError::from(#err))
+                        }
+                    });
+                    method.block = syn::parse2::<Block>(new_block).expect("invalid block");
+                    method.sig.output = syn::parse2::<ReturnType>(
+                        quote! { -> #crate_name::FieldResult<#inner_ty> },
+                    )
+                    .expect("invalid result type");
                 }
 
                 method.block =
@@ -42,6 +42,126 @@ pub enum Value {
     Upload(UploadValue),
 }
 
+impl Value {
+    /// Looks up a value by a JSON Pointer, such as `/a/b/2/c`.
+    ///
+    /// A JSON Pointer is a Unicode string with the reference tokens separated by `/`,
+    /// the `~` and `/` characters in the tokens are escaped as `~0` and `~1`. It is defined in
+    /// [RFC6901](https://tools.ietf.org/html/rfc6901).
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        parse_pointer(pointer)?
+            .into_iter()
+            .try_fold(self, |target, token| target.get_token(&token))
+    }
+
+    /// Looks up a value by a JSON Pointer and returns a mutable reference to that value.
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        parse_pointer(pointer)?
+            .into_iter()
+            .try_fold(self, |target, token| target.get_token_mut(&token))
+    }
+
+    /// Looks up a value by a path, such as `a.b[2].c`.
+    ///
+    /// The fields of an object are separated by `.`, and the indexes of a list are enclosed in `[]`.
+    pub fn get_path(&self, path: &str) -> Option<&Value> {
+        parse_path(path)?
+            .into_iter()
+            .try_fold(self, |target, token| target.get_token(token))
+    }
+
+    /// Looks up a value by a path and returns a mutable reference to that value.
+    pub fn get_path_mut(&mut self, path: &str) -> Option<&mut Value> {
+        parse_path(path)?
+            .into_iter()
+            .try_fold(self, |target, token| target.get_token_mut(token))
+    }
+
+    /// Replaces the value at the path with `value`, and returns the previous value.
+    ///
+    /// Returns `None` if the path does not exist.
+    pub fn set_path(&mut self, path: &str, value: Value) -> Option<Value> {
+        self.get_path_mut(path)
+            .map(|target| std::mem::replace(target, value))
+    }
+
+    /// Takes the value out of the `Value`, leaving a `Null` in its place.
+    pub fn take(&mut self) -> Value {
+        std::mem::replace(self, Value::Null)
+    }
+
+    fn get_token(&self, token: &str) -> Option<&Value> {
+        match self {
+            Value::Object(obj) => obj.get(token),
+            Value::List(ls) => parse_index(token).and_then(|idx| ls.get(idx)),
+            _ => None,
+        }
+    }
+
+    fn get_token_mut(&mut self, token: &str) -> Option<&mut Value> {
+        match self {
+            Value::Object(obj) => obj.get_mut(token),
+            Value::List(ls) => parse_index(token).and_then(move |idx| ls.get_mut(idx)),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an array index, leading `+` and leading zeros are not allowed as defined in RFC6901.
+fn parse_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || !token.bytes().all(|c| c.is_ascii_digit())
+        || (token.len() > 1 && token.starts_with('0'))
+    {
+        return None;
+    }
+    token.parse().ok()
+}
+
+fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    if !pointer.starts_with('/') {
+        return None;
+    }
+    Some(
+        pointer[1..]
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect(),
+    )
+}
+
+fn parse_path(path: &str) -> Option<Vec<&str>> {
+    let mut tokens = Vec::new();
+    if path.is_empty() {
+        return Some(tokens);
+    }
+    for (idx, part) in path.split('.').enumerate() {
+        let (name, mut indexes) = match part.find('[') {
+            Some(pos) => (&part[..pos], &part[pos..]),
+            None => (part, ""),
+        };
+        // Only the first part can start with an index, e.g. `[0].a`
+        if name.is_empty() && (idx > 0 || indexes.is_empty()) {
+            return None;
+        }
+        if !name.is_empty() {
+            tokens.push(name);
+        }
+        while !indexes.is_empty() {
+            if !indexes.starts_with('[') {
+                return None;
+            }
+            let end = indexes.find(']')?;
+            tokens.push(&indexes[1..end]);
+            indexes = &indexes[end + 1..];
+        }
+    }
+    Some(tokens)
+}
+
 impl serde::Serialize for Value {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -219,3 +339,66 @@ impl From<serde_json::Value> for Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value() -> Value {
+        serde_json::json!({
+            "a": {
+                "b": [1, 2, {"c": "hello"}],
+                "d/e": 10,
+            }
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_pointer() {
+        let mut value = value();
+        assert_eq!(value.pointer(""), Some(&value.clone()));
+        assert_eq!(
+            value.pointer("/a/b/2/c"),
+            Some(&Value::String("hello".to_string()))
+        );
+        assert_eq!(value.pointer("/a/d~1e"), Some(&Value::Number(10.into())));
+        assert_eq!(value.pointer("/a/b/3"), None);
+        assert_eq!(value.pointer("a/b"), None);
+        assert_eq!(value.pointer("/a/b/+1"), None);
+        assert_eq!(value.pointer("/a/b/01"), None);
+        assert_eq!(value.pointer("/a/b/-1"), None);
+        assert_eq!(value.pointer("/a/b/0"), Some(&Value::Number(1.into())));
+
+        *value.pointer_mut("/a/b/0").unwrap() = Value::Boolean(true);
+        assert_eq!(value.pointer("/a/b/0"), Some(&Value::Boolean(true)));
+    }
+
+    #[test]
+    fn test_path() {
+        let mut value = value();
+        assert_eq!(
+            value.get_path("a.b[2].c"),
+            Some(&Value::String("hello".to_string()))
+        );
+        assert_eq!(value.get_path("a.b[1]"), Some(&Value::Number(2.into())));
+        assert_eq!(value.get_path("a.x"), None);
+        assert_eq!(value.get_path("a.b[1"), None);
+        assert_eq!(value.get_path("a..b"), None);
+        assert_eq!(
+            Value::List(vec![value.clone()]).get_path("[0].a.b[0]"),
+            Some(&Value::Number(1.into()))
+        );
+
+        assert_eq!(
+            value.set_path("a.b[2].c", Value::String("world".to_string())),
+            Some(Value::String("hello".to_string()))
+        );
+        assert_eq!(
+            value.get_path_mut("a.b[2].c").map(Value::take),
+            Some(Value::String("world".to_string()))
+        );
+        assert_eq!(value.get_path("a.b[2].c"), Some(&Value::Null));
+        assert_eq!(value.set_path("a.x", Value::Null), None);
+    }
+}
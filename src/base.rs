@@ -99,6 +99,48 @@ pub trait ObjectType: OutputValueType {
     }
 }
 
+/// Represents a GraphQL object that can be returned as a trait object
+///
+/// It is implemented for all GraphQL objects, so that a trait object such as `Box<dyn MyTrait>`
+/// (where `MyTrait: DynObjectType`) can be used as a union or interface with the
+/// [`dyn_union`](macro.dyn_union.html) and [`dyn_interface`](macro.dyn_interface.html) macros.
+#[async_trait::async_trait]
+pub trait DynObjectType: Send + Sync {
+    /// Returns the name of the concrete GraphQL type.
+    fn resolve_type(&self) -> Cow<'static, str>;
+
+    /// Resolves a field value of the concrete GraphQL type.
+    async fn dyn_resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value>;
+
+    /// Collect the fields with the `name` inline object of the concrete GraphQL type.
+    fn dyn_collect_inline_fields<'a>(
+        &'a self,
+        name: &str,
+        ctx: &ContextSelectionSet<'a>,
+        futures: &mut Vec<BoxFieldFuture<'a>>,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType + Send + Sync> DynObjectType for T {
+    fn resolve_type(&self) -> Cow<'static, str> {
+        self.introspection_type_name()
+    }
+
+    async fn dyn_resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        self.resolve_field(ctx).await
+    }
+
+    fn dyn_collect_inline_fields<'a>(
+        &'a self,
+        name: &str,
+        ctx: &ContextSelectionSet<'a>,
+        futures: &mut Vec<BoxFieldFuture<'a>>,
+    ) -> Result<()> {
+        self.collect_inline_fields(name, ctx, futures)
+    }
+}
+
 /// Represents a GraphQL input object
 pub trait InputObjectType: InputValueType {}
 
@@ -135,7 +177,9 @@ pub trait ScalarType: Sized + Send {
 
     /// Checks for a valid scalar value.
     ///
-    /// Implementing this function can find incorrect input values during the verification phase, which can improve performance.
+    /// It is called for the literals in the query during the validation phase, so that incorrect input values
+    /// are reported with their location before execution.
+    /// The default implementation accepts any value, so that the error returned by `parse` is reported during execution.
     fn is_valid(_value: &Value) -> bool {
         true
     }
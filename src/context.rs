@@ -263,6 +263,7 @@ pub struct ContextBase<'a, T> {
     pub item: T,
     pub(crate) schema_env: &'a SchemaEnv,
     pub(crate) query_env: &'a QueryEnv,
+    pub(crate) in_introspection: bool,
 }
 
 impl<'a, T> Deref for ContextBase<'a, T> {
@@ -324,10 +325,26 @@ impl QueryEnv {
             item,
             schema_env,
             query_env: self,
+            in_introspection: false,
         }
     }
 }
 
+/// Returns the key of the field in the response, the key casing policy is not applied to aliases
+/// and introspection fields.
+fn response_key<'a>(
+    schema_env: &'a SchemaEnv,
+    field: &'a Positioned<Field>,
+    in_introspection: bool,
+) -> &'a str {
+    if let Some(alias) = &field.alias {
+        return alias.as_str();
+    }
+    schema_env
+        .registry
+        .response_key(field.name.as_str(), in_introspection)
+}
+
 impl<'a, T> ContextBase<'a, T> {
     fn get_child_resolve_id(&self) -> ResolveId {
         let id = self
@@ -345,22 +362,22 @@ impl<'a, T> ContextBase<'a, T> {
         &'a self,
         field: &'a Positioned<Field>,
     ) -> ContextBase<'a, &'a Positioned<Field>> {
+        let in_introspection = self.in_introspection || field.name.starts_with("__");
         ContextBase {
             path_node: Some(QueryPathNode {
                 parent: self.path_node.as_ref(),
-                segment: QueryPathSegment::Name(
-                    field
-                        .alias
-                        .as_ref()
-                        .map(|alias| alias.as_str())
-                        .unwrap_or_else(|| field.name.as_str()),
-                ),
+                segment: QueryPathSegment::Name(response_key(
+                    self.schema_env,
+                    field,
+                    in_introspection,
+                )),
             }),
             item: field,
             resolve_id: self.get_child_resolve_id(),
             inc_resolve_id: self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            in_introspection,
         }
     }
 
@@ -376,6 +393,7 @@ impl<'a, T> ContextBase<'a, T> {
             inc_resolve_id: &self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            in_introspection: self.in_introspection,
         }
     }
 
@@ -533,6 +551,7 @@ impl<'a> ContextBase<'a, &'a Positioned<SelectionSet>> {
             inc_resolve_id: self.inc_resolve_id,
             schema_env: self.schema_env,
             query_env: self.query_env,
+            in_introspection: self.in_introspection,
         }
     }
 }
@@ -570,12 +589,8 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
     }
 
     #[doc(hidden)]
-    pub fn result_name(&self) -> &str {
-        self.item
-            .alias
-            .as_ref()
-            .map(|alias| alias.as_str())
-            .unwrap_or_else(|| self.item.name.as_str())
+    pub fn result_name(&self) -> &'a str {
+        response_key(self.schema_env, self.item, self.in_introspection)
     }
 
     /// Get the position of the current field in the query code.
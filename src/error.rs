@@ -72,7 +72,10 @@ impl FieldError {
 }
 
 /// FieldResult type
-pub type FieldResult<T> = std::result::Result<T, FieldError>;
+///
+/// The error type can be any type that implements `Display + Send + Sync`, it is converted to
+/// `FieldError` when returned from a resolver, or by the function specified by `#[field(map_err = "...")]`.
+pub type FieldResult<T, E = FieldError> = std::result::Result<T, E>;
 
 impl<E> From<E> for FieldError
 where
@@ -277,6 +280,30 @@ pub enum QueryError {
 
     #[error("\"__typename\" must be an existing string")]
     TypeNameNotExists,
+
+    #[error("PersistedQueryNotSupported")]
+    PersistedQueryNotSupported,
+
+    #[error("PersistedQueryNotFound")]
+    PersistedQueryNotFound,
+
+    #[error("Provided sha does not match query")]
+    PersistedQueryHashMismatch,
+
+    #[error("Only persisted documents are allowed")]
+    PersistedDocumentsOnly,
+
+    #[error("Document storage error: {reason}")]
+    DocumentStorage { reason: String },
+
+    #[error("Type \"{object}\" is not a registered possible type of \"{abstract_type}\".")]
+    UnregisteredPossibleType {
+        /// Union or interface name
+        abstract_type: String,
+
+        /// Object name
+        object: String,
+    },
 }
 
 impl QueryError {
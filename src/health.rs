@@ -0,0 +1,224 @@
+use crate::registry::{MetaField, MetaType, Registry};
+use crate::{FieldResult, Type};
+use async_graphql_derive::{Enum, SimpleObject};
+use futures::future::{self, BoxFuture, Either};
+use futures::{Future, FutureExt};
+use futures_timer::Delay;
+use std::time::{Duration, Instant};
+
+type BoxReadinessCheck = Box<dyn Fn() -> BoxFuture<'static, FieldResult<()>> + Send + Sync>;
+
+/// The health of the service
+#[Enum(internal, name = "_HealthStatus")]
+enum HealthStatus {
+    /// All the readiness checks have passed.
+    Up,
+
+    /// At least one readiness check has failed.
+    Down,
+}
+
+/// The result of a readiness check
+#[SimpleObject(internal, name = "_ReadinessCheck")]
+struct ReadinessCheckResult {
+    /// The name of the check.
+    name: String,
+
+    /// Is the dependency ready?
+    ready: bool,
+
+    /// The error message if the dependency is not ready.
+    error: Option<String>,
+}
+
+/// Service health
+#[SimpleObject(internal, name = "_Health")]
+pub(crate) struct Health {
+    /// The health of the service.
+    status: HealthStatus,
+
+    /// The version of the service.
+    version: Option<String>,
+
+    /// The number of seconds since the service started.
+    uptime: u64,
+
+    /// The results of the readiness checks.
+    checks: Vec<ReadinessCheckResult>,
+}
+
+/// Service health and readiness checks
+///
+/// Adds a `_health` field to the query root, so that orchestrators can probe the GraphQL endpoint
+/// itself. The status is `UP` only if all the readiness checks pass.
+///
+/// The readiness checks are not cancelled by default, so a check that never completes blocks the
+/// `_health` query. Use `ServiceHealth::timeout` to bound the time of each check.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self) -> i32 {
+///         100
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .service_health(
+///             ServiceHealth::new()
+///                 .version("1.0.0")
+///                 .readiness_check("database", || async { Ok(()) })
+///                 .readiness_check("broker", || async { Err("connection refused".into()) }),
+///         )
+///         .finish();
+///     let res = schema.execute("{ _health { status version checks { name ready error } } }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({
+///         "_health": {
+///             "status": "DOWN",
+///             "version": "1.0.0",
+///             "checks": [
+///                 { "name": "database", "ready": true, "error": null },
+///                 { "name": "broker", "ready": false, "error": "connection refused" },
+///             ]
+///         }
+///     }));
+/// }
+/// ```
+pub struct ServiceHealth {
+    version: Option<String>,
+    started_at: Instant,
+    timeout: Option<Duration>,
+    hide_errors: bool,
+    checks: Vec<(String, BoxReadinessCheck)>,
+}
+
+impl Default for ServiceHealth {
+    fn default() -> Self {
+        Self {
+            version: None,
+            started_at: Instant::now(),
+            timeout: None,
+            hide_errors: false,
+            checks: Vec::new(),
+        }
+    }
+}
+
+impl ServiceHealth {
+    /// Create a service health, the uptime is counted from now.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the version of the service.
+    pub fn version(self, version: impl Into<String>) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// Set the timeout of each readiness check, a check that does not complete in time is not ready.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Hide the error messages of the readiness checks, the failed checks only report `ready: false`.
+    pub fn hide_errors(self) -> Self {
+        Self {
+            hide_errors: true,
+            ..self
+        }
+    }
+
+    /// Register a readiness check, such as checking the connection to a database or to the message
+    /// broker used by subscriptions. The check returns an error if the dependency is not ready.
+    ///
+    /// The checks are executed concurrently each time `_health` is queried.
+    ///
+    /// `_health` can be queried by any client, and the error message is returned as is. Make sure
+    /// it does not contain sensitive information such as hosts or credentials, or use
+    /// `ServiceHealth::hide_errors`.
+    pub fn readiness_check<F, R>(mut self, name: impl Into<String>, check: F) -> Self
+    where
+        F: Fn() -> R + Send + Sync + 'static,
+        R: Future<Output = FieldResult<()>> + Send + 'static,
+    {
+        self.checks
+            .push((name.into(), Box::new(move || check().boxed())));
+        self
+    }
+
+    pub(crate) fn register(registry: &mut Registry) -> Result<(), String> {
+        let ty = Health::create_type_info(registry);
+        if let Some(MetaType::Object { name, fields, .. }) =
+            registry.types.get_mut(&registry.query_type)
+        {
+            if fields.contains_key("_health") {
+                return Err(format!(
+                    "Field \"_health\" of type \"{}\" is already defined.",
+                    name
+                ));
+            }
+            fields.insert(
+                "_health".to_string(),
+                MetaField {
+                    name: "_health".to_string(),
+                    description: Some("The health of the service."),
+                    args: Default::default(),
+                    ty: format!("{}!", ty),
+                    deprecation: None,
+                    cache_control: Default::default(),
+                    external: false,
+                    requires: None,
+                    provides: None,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn health(&self) -> Health {
+        let checks = future::join_all(self.checks.iter().map(|(name, check)| async move {
+            let res = match self.timeout {
+                Some(timeout) => match future::select(check(), Delay::new(timeout)).await {
+                    Either::Left((res, _)) => res,
+                    Either::Right(_) => Err("timeout".into()),
+                },
+                None => check().await,
+            };
+            ReadinessCheckResult {
+                name: name.clone(),
+                ready: res.is_ok(),
+                error: if self.hide_errors {
+                    None
+                } else {
+                    res.err().map(|err| err.0)
+                },
+            }
+        }))
+        .await;
+
+        Health {
+            status: if checks.iter().all(|check| check.ready) {
+                HealthStatus::Up
+            } else {
+                HealthStatus::Down
+            },
+            version: self.version.clone(),
+            uptime: self.started_at.elapsed().as_secs(),
+            checks,
+        }
+    }
+}
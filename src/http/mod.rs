@@ -24,6 +24,7 @@ use serde::{Serialize, Serializer};
 #[derive(Deserialize, Clone, PartialEq, Debug)]
 pub struct GQLRequest {
     /// Query source
+    #[serde(default)]
     pub query: String,
 
     /// Operation name for this query
@@ -32,6 +33,9 @@ pub struct GQLRequest {
 
     /// Variables for this query
     pub variables: Option<serde_json::Value>,
+
+    /// Extensions for this query, such as `persistedQuery`
+    pub extensions: Option<serde_json::Value>,
 }
 
 #[async_trait::async_trait]
@@ -49,10 +53,26 @@ impl IntoQueryBuilder for GQLRequest {
                 builder = builder.variables(variables);
             }
         }
+        if let Some(hash) = self.extensions.and_then(persisted_query_hash) {
+            builder = builder.persisted_query_hash(hash);
+        }
         Ok(builder)
     }
 }
 
+fn persisted_query_hash(extensions: serde_json::Value) -> Option<String> {
+    // The extensions of a GET request are JSON-encoded in the query string
+    let extensions = match extensions {
+        serde_json::Value::String(s) => serde_json::from_str(&s).ok()?,
+        extensions => extensions,
+    };
+    extensions
+        .get("persistedQuery")?
+        .get("sha256Hash")?
+        .as_str()
+        .map(ToString::to_string)
+}
+
 /// Serializable GraphQL Response object
 pub struct GQLResponse(pub Result<QueryResponse>);
 
@@ -208,6 +228,24 @@ mod tests {
         assert_eq!(request.query, "{ a b c }");
     }
 
+    #[test]
+    fn test_request_with_persisted_query() {
+        let request: GQLRequest = serde_json::from_value(json! ({
+            "extensions": {
+                "persistedQuery": {
+                    "version": 1,
+                    "sha256Hash": "abc",
+                }
+            }
+        }))
+        .unwrap();
+        assert_eq!(request.query, "");
+        assert_eq!(
+            request.extensions.and_then(persisted_query_hash).as_deref(),
+            Some("abc")
+        );
+    }
+
     #[test]
     fn test_response_data() {
         let resp = GQLResponse(Ok(QueryResponse {
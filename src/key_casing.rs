@@ -0,0 +1,48 @@
+use inflector::Inflector;
+use std::borrow::Cow;
+
+/// Casing policy for the keys of the response data
+///
+/// The policy is applied when the keys of the response objects are generated, so there is no
+/// additional traversal of the response. Aliases specified in the query and the results of
+/// introspection fields (`__typename`, `__schema`, `__type` and everything below them) are
+/// never renamed, so that the introspection result stays consistent with the schema.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeyCasing {
+    /// camelCase
+    CamelCase,
+
+    /// snake_case
+    SnakeCase,
+
+    /// PascalCase
+    PascalCase,
+
+    /// SCREAMING_SNAKE_CASE
+    ScreamingSnakeCase,
+
+    /// kebab-case
+    KebabCase,
+}
+
+impl KeyCasing {
+    /// Converts a field name according to this policy.
+    ///
+    /// Leading underscores are preserved, so `_service` is never converted to `service`.
+    pub fn convert<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let body = name.trim_start_matches('_');
+        let prefix = &name[..name.len() - body.len()];
+        let new_body = match self {
+            KeyCasing::CamelCase => body.to_camel_case(),
+            KeyCasing::SnakeCase => body.to_snake_case(),
+            KeyCasing::PascalCase => body.to_pascal_case(),
+            KeyCasing::ScreamingSnakeCase => body.to_screaming_snake_case(),
+            KeyCasing::KebabCase => body.to_kebab_case(),
+        };
+        if new_body == body {
+            Cow::Borrowed(name)
+        } else {
+            Cow::Owned(format!("{}{}", prefix, new_body))
+        }
+    }
+}
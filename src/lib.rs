@@ -47,6 +47,8 @@
 //! * Limit query complexity/depth
 //! * Error Extensions
 //! * Apollo Federation
+//! * Automatic persisted queries and persisted documents
+//! * Health and readiness checks
 //!
 //! ## Integrations
 //!
@@ -109,6 +111,8 @@ extern crate log;
 mod base;
 mod context;
 mod error;
+mod health;
+mod key_casing;
 mod look_ahead;
 mod model;
 mod mutation_resolver;
@@ -122,6 +126,7 @@ mod validation;
 
 pub mod extensions;
 pub mod guard;
+pub mod persisted_documents;
 pub mod validators;
 
 #[doc(hidden)]
@@ -140,7 +145,7 @@ pub use serde_json;
 
 pub mod http;
 
-pub use base::{ScalarType, Type};
+pub use base::{DynObjectType, ScalarType, Type};
 pub use context::{
     Context, ContextBase, Data, QueryEnv, QueryPathNode, QueryPathSegment, Variables,
 };
@@ -148,6 +153,8 @@ pub use error::{
     Error, ErrorExtensions, FieldError, FieldResult, InputValueError, InputValueResult,
     ParseRequestError, QueryError, ResultExt, RuleError,
 };
+pub use health::ServiceHealth;
+pub use key_casing::KeyCasing;
 pub use look_ahead::Lookahead;
 pub use parser::{Pos, Positioned, Value};
 pub use query::{IntoQueryBuilder, IntoQueryBuilderOpts, QueryBuilder, QueryResponse};
@@ -178,7 +185,7 @@ pub use resolver::{collect_fields, do_resolve};
 #[doc(hidden)]
 pub use subscription::SubscriptionType;
 #[doc(hidden)]
-pub use types::{EnumItem, EnumType};
+pub use types::{check_possible_type, EnumItem, EnumType};
 
 /// Define a GraphQL object
 ///
@@ -204,6 +211,7 @@ pub use types::{EnumItem, EnumType};
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | map_err       | Function used to convert the error returned by the field resolver, the result must be a `FieldError` or implement `Display` | code string | Y |
 ///
 /// # Field argument parameters
 ///
@@ -226,6 +234,8 @@ pub use types::{EnumItem, EnumType};
 /// - Object and &Object
 /// - Enum
 /// - FieldResult<T, E>, such as `FieldResult<i32, E>`
+/// - Result<T, E>, such as `Result<i32, E>`, the error is converted to `FieldError` if it implements `Display`
+///   (the error type must be written out, so aliases such as `io::Result<T>` are not supported)
 ///
 /// # Context
 ///
@@ -713,6 +723,7 @@ pub use async_graphql_derive::GQLUnion;
 /// | deprecation | Field deprecation reason  | string   | Y        |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | map_err       | Function used to convert the error returned by the field resolver, the result must be a `FieldError` or implement `Display` | code string | Y |
 ///
 /// # Field argument parameters
 ///
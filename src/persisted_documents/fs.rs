@@ -0,0 +1,68 @@
+use crate::persisted_documents::{
+    document_hash, DocumentStorage, DocumentStorageError, DocumentStorageResult,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// Read-only document storage loaded from a manifest directory
+///
+/// Every `.graphql` file in the directory whose name is a SHA-256 hash is a document, e.g.
+/// `<sha256 hash>.graphql`, other files are ignored. Loading fails if the hash of a document does
+/// not match its file name.
+/// The directory is read once when the storage is created, so it is mostly used in persisted
+/// documents only mode.
+pub struct FsStorage {
+    documents: HashMap<String, String>,
+}
+
+impl FsStorage {
+    /// Load all documents from the manifest directory.
+    pub fn new<P: AsRef<Path>>(dir: P) -> std::io::Result<Self> {
+        let mut documents = HashMap::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("graphql") {
+                continue;
+            }
+            let hash = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(hash) if is_hash(hash) => hash,
+                _ => continue,
+            };
+            let document = std::fs::read_to_string(&path)?;
+            if document_hash(&document) != hash {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "The hash of the document \"{}\" does not match its file name",
+                        path.display()
+                    ),
+                ));
+            }
+            documents.insert(hash.to_string(), document);
+        }
+        Ok(Self { documents })
+    }
+}
+
+fn is_hash(s: &str) -> bool {
+    s.len() == 64
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+#[async_trait::async_trait]
+impl DocumentStorage for FsStorage {
+    async fn get(&self, hash: &str) -> DocumentStorageResult<Option<String>> {
+        Ok(self.documents.get(hash).cloned())
+    }
+
+    async fn set(
+        &self,
+        _hash: &str,
+        _document: &str,
+        _ttl: Option<Duration>,
+    ) -> DocumentStorageResult<()> {
+        Err(DocumentStorageError::ReadOnly)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::persisted_documents::{document_hash, DocumentStorage, DocumentStorageResult};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const DEFAULT_CAPACITY: usize = 1000;
+
+struct Entry {
+    document: String,
+    deadline: Option<Instant>,
+    last_used: u64,
+}
+
+struct Documents {
+    entries: HashMap<String, Entry>,
+    capacity: Option<usize>,
+    tick: u64,
+}
+
+/// In-memory document storage
+///
+/// The number of documents is bounded by the capacity. When a document is stored in a full
+/// storage, the expired documents are removed first, and then the least recently used one.
+/// The default capacity is 1000.
+pub struct MemoryStorage {
+    documents: spin::Mutex<Documents>,
+}
+
+impl Default for MemoryStorage {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl MemoryStorage {
+    /// Create an in-memory storage that holds at most `capacity` documents.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            documents: spin::Mutex::new(Documents {
+                entries: HashMap::new(),
+                capacity: Some(capacity.max(1)),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Create an in-memory storage that contains the specified documents, they are stored by their
+    /// SHA-256 hash.
+    ///
+    /// The storage is unbounded, so it should only be used with `SchemaBuilder::persisted_documents_only`.
+    pub fn with_documents<I, D>(documents: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<String>,
+    {
+        Self {
+            documents: spin::Mutex::new(Documents {
+                entries: documents
+                    .into_iter()
+                    .map(|document| {
+                        let document = document.into();
+                        (
+                            document_hash(&document),
+                            Entry {
+                                document,
+                                deadline: None,
+                                last_used: 0,
+                            },
+                        )
+                    })
+                    .collect(),
+                capacity: None,
+                tick: 0,
+            }),
+        }
+    }
+}
+
+impl Documents {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn evict(&mut self) {
+        let capacity = match self.capacity {
+            Some(capacity) => capacity,
+            None => return,
+        };
+        if self.entries.len() < capacity {
+            return;
+        }
+
+        let now = Instant::now();
+        self.entries.retain(|_, entry| {
+            entry
+                .deadline
+                .map(|deadline| deadline > now)
+                .unwrap_or(true)
+        });
+
+        while self.entries.len() >= capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(hash, _)| hash.clone());
+            match lru {
+                Some(hash) => {
+                    self.entries.remove(&hash);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DocumentStorage for MemoryStorage {
+    async fn get(&self, hash: &str) -> DocumentStorageResult<Option<String>> {
+        let mut documents = self.documents.lock();
+        let tick = documents.next_tick();
+        let expired = match documents.entries.get(hash) {
+            Some(entry) => entry
+                .deadline
+                .map(|deadline| deadline <= Instant::now())
+                .unwrap_or_default(),
+            None => return Ok(None),
+        };
+        if expired {
+            documents.entries.remove(hash);
+            return Ok(None);
+        }
+        Ok(documents.entries.get_mut(hash).map(|entry| {
+            entry.last_used = tick;
+            entry.document.clone()
+        }))
+    }
+
+    async fn set(
+        &self,
+        hash: &str,
+        document: &str,
+        ttl: Option<Duration>,
+    ) -> DocumentStorageResult<()> {
+        let mut documents = self.documents.lock();
+        let tick = documents.next_tick();
+        if !documents.entries.contains_key(hash) {
+            documents.evict();
+        }
+        documents.entries.insert(
+            hash.to_string(),
+            Entry {
+                document: document.to_string(),
+                deadline: ttl.map(|ttl| Instant::now() + ttl),
+                last_used: tick,
+            },
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+//! Persisted documents
+//!
+//! A `DocumentStorage` stores GraphQL documents by their SHA-256 hash, it is used by the
+//! [Automatic Persisted Queries](https://www.apollographql.com/docs/apollo-server/performance/apq/)
+//! (`SchemaBuilder::automatic_persisted_queries`) and the persisted documents only mode
+//! (`SchemaBuilder::persisted_documents_only`).
+
+#[cfg(feature = "fs-storage")]
+mod fs;
+mod memory;
+#[cfg(feature = "redis-storage")]
+mod redis;
+
+use crate::{Pos, QueryError, Result};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "fs-storage")]
+pub use self::fs::FsStorage;
+pub use self::memory::MemoryStorage;
+#[cfg(feature = "redis-storage")]
+pub use self::redis::RedisStorage;
+
+/// Error for document storage
+#[derive(Debug, Error)]
+pub enum DocumentStorageError {
+    /// IO error.
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+
+    /// The storage does not support storing documents.
+    #[error("Document storage is read-only")]
+    ReadOnly,
+
+    /// Custom storage error.
+    #[error("{0}")]
+    Custom(String),
+}
+
+/// DocumentStorageResult type
+pub type DocumentStorageResult<T> = std::result::Result<T, DocumentStorageError>;
+
+/// Storage of the persisted documents
+#[async_trait::async_trait]
+pub trait DocumentStorage: Send + Sync + 'static {
+    /// Get the document by hash.
+    async fn get(&self, hash: &str) -> DocumentStorageResult<Option<String>>;
+
+    /// Store the document by hash, it expires after `ttl`, `None` means it never expires.
+    async fn set(
+        &self,
+        hash: &str,
+        document: &str,
+        ttl: Option<Duration>,
+    ) -> DocumentStorageResult<()>;
+}
+
+/// Returns the SHA-256 hash of the document in lowercase hex.
+pub fn document_hash(document: &str) -> String {
+    format!("{:x}", Sha256::digest(document.as_bytes()))
+}
+
+#[derive(Clone)]
+pub(crate) struct PersistedDocuments {
+    pub(crate) storage: Arc<dyn DocumentStorage>,
+    pub(crate) only: bool,
+    pub(crate) ttl: Option<Duration>,
+}
+
+impl PersistedDocuments {
+    /// Returns the query source to be executed.
+    pub(crate) async fn resolve(&self, query_source: String, hash: Option<&str>) -> Result<String> {
+        match hash {
+            Some(hash) if query_source.is_empty() => match self.get(hash).await? {
+                Some(document) => Ok(document),
+                None => Err(QueryError::PersistedQueryNotFound.into_error(Pos::default())),
+            },
+            Some(hash) => {
+                if document_hash(&query_source) != hash {
+                    return Err(QueryError::PersistedQueryHashMismatch.into_error(Pos::default()));
+                }
+                if self.only {
+                    if self.get(hash).await?.is_none() {
+                        return Err(QueryError::PersistedDocumentsOnly.into_error(Pos::default()));
+                    }
+                } else if let Err(err) = self.storage.set(hash, &query_source, self.ttl).await {
+                    // The full query was sent, so it can still be executed.
+                    warn!(target: "async-graphql", "Failed to store the persisted query \"{}\": {}", hash, err);
+                }
+                Ok(query_source)
+            }
+            None if self.only => {
+                if self.get(&document_hash(&query_source)).await?.is_none() {
+                    return Err(QueryError::PersistedDocumentsOnly.into_error(Pos::default()));
+                }
+                Ok(query_source)
+            }
+            None => Ok(query_source),
+        }
+    }
+
+    async fn get(&self, hash: &str) -> Result<Option<String>> {
+        self.storage.get(hash).await.map_err(storage_error)
+    }
+}
+
+fn storage_error(err: DocumentStorageError) -> crate::Error {
+    QueryError::DocumentStorage {
+        reason: err.to_string(),
+    }
+    .into_error(Pos::default())
+}
@@ -0,0 +1,66 @@
+use crate::persisted_documents::{DocumentStorage, DocumentStorageError, DocumentStorageResult};
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use std::time::Duration;
+
+/// Redis document storage
+///
+/// Documents are stored as string values with the key `<prefix><hash>`.
+/// All requests share a single multiplexed connection.
+pub struct RedisStorage {
+    conn: MultiplexedConnection,
+    prefix: String,
+}
+
+impl RedisStorage {
+    /// Create a redis storage with a multiplexed connection, the default key prefix is `"apq:"`.
+    ///
+    /// The connection can be created with `redis::Client::get_multiplexed_tokio_connection` or
+    /// `redis::Client::get_multiplexed_async_std_connection`.
+    pub fn new(conn: MultiplexedConnection) -> Self {
+        Self {
+            conn,
+            prefix: "apq:".to_string(),
+        }
+    }
+
+    /// Set the key prefix.
+    pub fn prefix<T: Into<String>>(self, prefix: T) -> Self {
+        Self {
+            prefix: prefix.into(),
+            ..self
+        }
+    }
+}
+
+impl From<redis::RedisError> for DocumentStorageError {
+    fn from(err: redis::RedisError) -> Self {
+        DocumentStorageError::Custom(err.to_string())
+    }
+}
+
+#[async_trait::async_trait]
+impl DocumentStorage for RedisStorage {
+    async fn get(&self, hash: &str) -> DocumentStorageResult<Option<String>> {
+        let mut conn = self.conn.clone();
+        Ok(conn.get(format!("{}{}", self.prefix, hash)).await?)
+    }
+
+    async fn set(
+        &self,
+        hash: &str,
+        document: &str,
+        ttl: Option<Duration>,
+    ) -> DocumentStorageResult<()> {
+        let mut conn = self.conn.clone();
+        let key = format!("{}{}", self.prefix, hash);
+        let _: () = match ttl {
+            Some(ttl) => {
+                conn.set_ex(key, document, ttl.as_secs().max(1) as usize)
+                    .await?
+            }
+            None => conn.set(key, document).await?,
+        };
+        Ok(())
+    }
+}
@@ -53,6 +53,7 @@ pub struct QueryResponse {
 pub struct QueryBuilder {
     pub(crate) query_source: String,
     pub(crate) operation_name: Option<String>,
+    pub(crate) persisted_query_hash: Option<String>,
     pub(crate) variables: Variables,
     pub(crate) ctx_data: Option<Data>,
     extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
@@ -64,6 +65,7 @@ impl QueryBuilder {
         QueryBuilder {
             query_source: query_source.into(),
             operation_name: None,
+            persisted_query_hash: None,
             variables: Default::default(),
             ctx_data: None,
             extensions: Default::default(),
@@ -78,6 +80,16 @@ impl QueryBuilder {
         }
     }
 
+    /// Specify the SHA-256 hash of the persisted query.
+    ///
+    /// If the query source is empty, the query source will be loaded from the document storage.
+    pub fn persisted_query_hash<T: Into<String>>(self, hash: T) -> Self {
+        QueryBuilder {
+            persisted_query_hash: Some(hash.into()),
+            ..self
+        }
+    }
+
     /// Specify the variables.
     pub fn variables(self, variables: Variables) -> Self {
         QueryBuilder { variables, ..self }
@@ -129,8 +141,11 @@ impl QueryBuilder {
         Mutation: ObjectType + Send + Sync + 'static,
         Subscription: SubscriptionType + Send + Sync + 'static,
     {
+        let query_source = schema
+            .resolve_query_source(self.query_source, self.persisted_query_hash.as_deref())
+            .await?;
         let (mut document, cache_control, extensions) =
-            schema.prepare_query(&self.query_source, &self.variables, &self.extensions)?;
+            schema.prepare_query(&query_source, &self.variables, &self.extensions)?;
 
         // execute
         let inc_resolve_id = AtomicUsize::default();
@@ -166,6 +181,7 @@ impl QueryBuilder {
             item: &env.document.current_operation().selection_set,
             schema_env: &schema.env,
             query_env: &env,
+            in_introspection: false,
         };
 
         env.extensions.lock().execution_start();
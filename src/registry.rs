@@ -1,6 +1,6 @@
 use crate::parser::query::Type as ParsedType;
 use crate::validators::InputValueValidator;
-use crate::{model, Any, Type as _, Value};
+use crate::{model, Any, KeyCasing, Type as _, Value};
 use indexmap::map::IndexMap;
 use indexmap::set::IndexSet;
 use itertools::Itertools;
@@ -344,6 +344,7 @@ pub struct Registry {
     pub query_type: String,
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
+    pub response_keys: HashMap<String, String>,
 }
 
 impl Registry {
@@ -388,6 +389,161 @@ impl Registry {
             });
     }
 
+    pub fn add_possible_type(
+        &mut self,
+        abstract_type: &str,
+        ty: &str,
+    ) -> std::result::Result<(), String> {
+        if let Some(MetaType::Interface { fields, .. }) = self.types.get(abstract_type) {
+            self.check_implements(ty, abstract_type, fields)?;
+        }
+
+        match self.types.get_mut(abstract_type) {
+            Some(MetaType::Union { possible_types, .. }) => {
+                possible_types.insert(ty.to_string());
+            }
+            Some(MetaType::Interface { possible_types, .. }) => {
+                possible_types.insert(ty.to_string());
+                self.add_implements(ty, abstract_type);
+            }
+            _ => {
+                return Err(format!(
+                    "\"{}\" is not a union or interface.",
+                    abstract_type
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the object has all the fields of the interface, with compatible types.
+    fn check_implements(
+        &self,
+        ty: &str,
+        interface: &str,
+        interface_fields: &IndexMap<String, MetaField>,
+    ) -> std::result::Result<(), String> {
+        let fields = match self.types.get(ty) {
+            Some(MetaType::Object { fields, .. }) => fields,
+            _ => return Err(format!("\"{}\" is not an object.", ty)),
+        };
+        for interface_field in interface_fields.values() {
+            if interface_field.name.starts_with("__") {
+                continue;
+            }
+            match fields.get(&interface_field.name) {
+                Some(field) => {
+                    if !self.is_covariant_type(
+                        &MetaTypeName::create(&interface_field.ty),
+                        &MetaTypeName::create(&field.ty),
+                    ) {
+                        return Err(format!(
+                            "Field \"{}.{}\" has type \"{}\", which is not compatible with \"{}\" of interface \"{}\".",
+                            ty, field.name, field.ty, interface_field.ty, interface
+                        ));
+                    }
+                }
+                None => {
+                    return Err(format!(
+                        "Object \"{}\" does not have the field \"{}\" of interface \"{}\".",
+                        ty, interface_field.name, interface
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if a field of type `sub` can implement an interface field of type `super_type`.
+    fn is_covariant_type(&self, super_type: &MetaTypeName<'_>, sub: &MetaTypeName<'_>) -> bool {
+        match (super_type, sub) {
+            (MetaTypeName::NonNull(super_type), MetaTypeName::NonNull(sub))
+            | (MetaTypeName::Named(super_type), MetaTypeName::NonNull(sub))
+            | (MetaTypeName::List(super_type), MetaTypeName::List(sub)) => self.is_covariant_type(
+                &MetaTypeName::create(super_type),
+                &MetaTypeName::create(sub),
+            ),
+            (MetaTypeName::Named(super_type), MetaTypeName::Named(sub)) => {
+                super_type == sub
+                    || self
+                        .types
+                        .get(*super_type)
+                        .map(|ty| ty.is_possible_type(sub))
+                        .unwrap_or_default()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the response key of a field that is not aliased, the key casing policy is not
+    /// applied to the fields of the introspection types.
+    pub fn response_key<'a>(&'a self, name: &'a str, in_introspection: bool) -> &'a str {
+        if in_introspection {
+            return name;
+        }
+        self.response_keys
+            .get(name)
+            .map(|key| key.as_str())
+            .unwrap_or(name)
+    }
+
+    /// Records the response keys of the fields that are renamed by the key casing policy.
+    ///
+    /// Two fields of a type must not have the same response key.
+    pub(crate) fn apply_key_casing(
+        &mut self,
+        key_casing: KeyCasing,
+    ) -> std::result::Result<(), String> {
+        let mut response_keys = HashMap::new();
+        for ty in self.types.values() {
+            if ty.name().starts_with("__") {
+                continue;
+            }
+            let fields = match ty {
+                MetaType::Object { fields, .. } => fields,
+                MetaType::Interface { fields, .. } => fields,
+                _ => continue,
+            };
+            let mut keys = HashMap::new();
+            for name in fields.keys().filter(|name| !name.starts_with("__")) {
+                let key = key_casing.convert(name);
+                if let Some(other) = keys.insert(key.to_string(), name) {
+                    return Err(format!(
+                        "Fields \"{}\" and \"{}\" of type \"{}\" have the same response key \"{}\".",
+                        other,
+                        name,
+                        ty.name(),
+                        key
+                    ));
+                }
+                if key != name.as_str() {
+                    response_keys.insert(name.clone(), key.into_owned());
+                }
+            }
+        }
+        self.response_keys = response_keys;
+        Ok(())
+    }
+
+    /// Check that every union and interface has at least one possible type.
+    pub(crate) fn check_possible_types(&self) -> std::result::Result<(), String> {
+        for ty in self.types.values() {
+            let kind = match ty {
+                MetaType::Union { .. } => "Union",
+                MetaType::Interface { .. } => "Interface",
+                _ => continue,
+            };
+            if ty.possible_types().map(|types| types.is_empty()) == Some(true) {
+                return Err(format!(
+                    "{} \"{}\" has no possible types. Use `SchemaBuilder::register_possible_type` to register them.",
+                    kind,
+                    ty.name()
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub fn add_keys(&mut self, ty: &str, keys: &str) {
         let all_keys = match self.types.get_mut(ty) {
             Some(MetaType::Object { keys: all_keys, .. }) => all_keys,
@@ -418,7 +574,7 @@ impl Registry {
             if field.name.starts_with("__") {
                 continue;
             }
-            if field.name == "_service" || field.name == "_entities" {
+            if field.name == "_service" || field.name == "_entities" || field.name == "_health" {
                 continue;
             }
 
@@ -467,8 +623,13 @@ impl Registry {
                 keys,
                 ..
             } => {
-                if name == &self.query_type && fields.len() == 4 {
-                    // Is empty query root, only __schema, __type, _service, _entities fields
+                if name == &self.query_type
+                    && fields.keys().all(|name| {
+                        ["__schema", "__type", "_service", "_entities", "_health"]
+                            .contains(&name.as_str())
+                    })
+                {
+                    // Is empty query root, only __schema, __type, _service, _entities, _health fields
                     return;
                 }
                 if let Some(subscription_type) = &self.subscription_type {
@@ -556,8 +717,15 @@ impl Registry {
             if ty.name().starts_with("__") {
                 continue;
             }
-            const FEDERATION_TYPES: &[&str] = &["_Any", "_Entity", "_Service"];
-            if FEDERATION_TYPES.contains(&ty.name()) {
+            const INTERNAL_TYPES: &[&str] = &[
+                "_Any",
+                "_Entity",
+                "_Service",
+                "_Health",
+                "_HealthStatus",
+                "_ReadinessCheck",
+            ];
+            if INTERNAL_TYPES.contains(&ty.name()) {
                 continue;
             }
             self.create_federation_type(ty, &mut sdl);
@@ -565,18 +733,122 @@ impl Registry {
         sdl
     }
 
-    pub(crate) fn has_entities(&self) -> bool {
-        self.types.values().any(|ty| match ty {
+    /// Check that the federation entities are valid.
+    ///
+    /// At least one entity must be defined, and every field referenced by an entity key must exist on that entity.
+    fn entities(&self) -> impl Iterator<Item = (&MetaType, &Vec<String>)> {
+        self.types.values().filter_map(|ty| match ty {
             MetaType::Object {
                 keys: Some(keys), ..
-            } => !keys.is_empty(),
+            } if !keys.is_empty() => Some((ty, keys)),
             MetaType::Interface {
                 keys: Some(keys), ..
-            } => !keys.is_empty(),
-            _ => false,
+            } if !keys.is_empty() => Some((ty, keys)),
+            _ => None,
         })
     }
 
+    /// Returns true if at least one entity is defined with `#[entity]`.
+    pub(crate) fn has_entities(&self) -> bool {
+        self.entities().next().is_some()
+    }
+
+    pub(crate) fn check_federation(&self) -> std::result::Result<(), String> {
+        let mut entities = self.entities().collect_vec();
+
+        if entities.is_empty() {
+            return Err("Federation is enabled, but no entity is defined. Use `#[entity]` on a query root method to define at least one entity.".to_string());
+        }
+
+        entities.sort_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+        for (ty, keys) in entities {
+            for key in keys {
+                let mut tokens = key
+                    .replace('{', " { ")
+                    .replace('}', " } ")
+                    .split_whitespace()
+                    .map(ToString::to_string)
+                    .collect_vec()
+                    .into_iter()
+                    .peekable();
+                self.check_key_fields(ty, key, &mut tokens)?;
+                if let Some(token) = tokens.next() {
+                    return Err(format!(
+                        "Invalid key \"{}\" for entity \"{}\", unexpected \"{}\".",
+                        key,
+                        ty.name(),
+                        token
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_key_fields<I: Iterator<Item = String>>(
+        &self,
+        ty: &MetaType,
+        key: &str,
+        tokens: &mut std::iter::Peekable<I>,
+    ) -> std::result::Result<(), String> {
+        let mut count = 0;
+        while let Some(token) = tokens.peek() {
+            if token == "}" {
+                break;
+            }
+            if token == "{" {
+                return Err(format!(
+                    "Invalid key \"{}\" for entity \"{}\", missing field name before \"{{\".",
+                    key,
+                    ty.name()
+                ));
+            }
+
+            let field_name = tokens.next().unwrap();
+            let field = ty.field_by_name(&field_name).ok_or_else(|| {
+                format!(
+                    "Invalid key \"{}\" for entity \"{}\", field \"{}\" does not exist.",
+                    key,
+                    ty.name(),
+                    field_name
+                )
+            })?;
+            count += 1;
+
+            if tokens.peek().map(String::as_str) == Some("{") {
+                tokens.next();
+                let field_type = self
+                    .concrete_type_by_name(&field.ty)
+                    .filter(|field_type| field_type.is_composite())
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid key \"{}\" for entity \"{}\", field \"{}\" is not an object.",
+                            key,
+                            ty.name(),
+                            field_name
+                        )
+                    })?;
+                self.check_key_fields(field_type, key, tokens)?;
+                if tokens.next().as_deref() != Some("}") {
+                    return Err(format!(
+                        "Invalid key \"{}\" for entity \"{}\", missing \"}}\".",
+                        key,
+                        ty.name()
+                    ));
+                }
+            }
+        }
+
+        if count == 0 {
+            return Err(format!(
+                "Invalid key \"{}\" for entity \"{}\", the field set is empty.",
+                key,
+                ty.name()
+            ));
+        }
+        Ok(())
+    }
+
     fn create_entity_type(&mut self) {
         let possible_types = self
             .types
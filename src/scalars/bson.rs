@@ -12,6 +12,13 @@ impl ScalarType for ObjectId {
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => ObjectId::with_string(s).is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(self.to_string())
     }
@@ -23,6 +30,10 @@ impl ScalarType for UtcDateTime {
         DateTime::<Utc>::parse(value).map(UtcDateTime::from)
     }
 
+    fn is_valid(value: &Value) -> bool {
+        DateTime::<Utc>::is_valid(value)
+    }
+
     fn to_value(&self) -> Value {
         (**self).to_value()
     }
@@ -12,6 +12,13 @@ impl ScalarType for Tz {
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => Tz::from_str(s).is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(Tz::name(self))
     }
@@ -14,6 +14,13 @@ impl ScalarType for DateTime<Utc> {
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => s.parse::<DateTime<Utc>>().is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(self.to_rfc3339())
     }
@@ -11,6 +11,13 @@ impl ScalarType for NaiveDate {
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(self.format("%Y-%m-%d").to_string())
     }
@@ -11,6 +11,13 @@ impl ScalarType for NaiveTime {
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => NaiveTime::parse_from_str(s, "%H:%M:%S").is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(self.format("%H:%M:%S").to_string())
     }
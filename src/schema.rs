@@ -1,14 +1,16 @@
 use crate::context::Data;
 use crate::extensions::{BoxExtension, ErrorLogger, Extension, Extensions};
+use crate::health::ServiceHealth;
 use crate::model::__DirectiveLocation;
 use crate::parser::parse_query;
+use crate::persisted_documents::{DocumentStorage, PersistedDocuments};
 use crate::query::QueryBuilder;
 use crate::registry::{MetaDirective, MetaInputValue, Registry};
 use crate::subscription::{create_connection, create_subscription_stream, SubscriptionTransport};
 use crate::types::QueryRoot;
 use crate::validation::{check_rules, CheckResult, ValidationMode};
 use crate::{
-    CacheControl, Error, ObjectType, Pos, QueryEnv, QueryError, QueryResponse, Result,
+    CacheControl, Error, KeyCasing, ObjectType, Pos, QueryEnv, QueryError, QueryResponse, Result,
     SubscriptionType, Type, Variables, ID,
 };
 use async_graphql_parser::query::{Document, OperationType};
@@ -21,6 +23,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
@@ -34,6 +37,8 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     depth: Option<usize>,
     extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
     enable_federation: bool,
+    persisted_documents: Option<PersistedDocuments>,
+    key_casing: Option<KeyCasing>,
 }
 
 impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
@@ -45,12 +50,40 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Register `T` as a possible type of the union or interface `A`.
+    ///
+    /// It is used to add members to a union or interface defined with the
+    /// [`dyn_union`](macro.dyn_union.html) or [`dyn_interface`](macro.dyn_interface.html) macros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is not a union or interface, or if `A` is an interface and `T` does not have
+    /// all of its fields with compatible types.
+    pub fn register_possible_type<A: Type, T: ObjectType>(mut self) -> Self {
+        A::create_type_info(&mut self.registry);
+        T::create_type_info(&mut self.registry);
+        if let Err(err) = self
+            .registry
+            .add_possible_type(&A::type_name(), &T::type_name())
+        {
+            panic!("Failed to register possible type: {}", err);
+        }
+        self
+    }
+
     /// Disable introspection query
     pub fn disable_introspection(mut self) -> Self {
         self.query.disable_introspection = true;
         self
     }
 
+    /// Add a `_health` field to the query root, which returns the health of the service and the
+    /// results of the readiness checks.
+    pub fn service_health(mut self, health: ServiceHealth) -> Self {
+        self.query.health = Some(health);
+        self
+    }
+
     /// Set limit complexity, Default no limit.
     pub fn limit_complexity(mut self, complexity: usize) -> Self {
         self.complexity = Some(complexity);
@@ -85,17 +118,96 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
-    /// Enable federation, which is automatically enabled if the Query has least one entity definition.
+    /// Enable Apollo Federation, adds the `_service` and `_entities` fields to the query root.
+    ///
+    /// The Query must have at least one entity definition, and every key of an entity must refer to
+    /// an existing field of that entity, otherwise `finish` will panic. It must be called if the
+    /// Query has entity definitions.
     pub fn enable_federation(mut self) -> Self {
         self.enable_federation = true;
         self
     }
 
+    /// Enable [Automatic Persisted Queries](https://www.apollographql.com/docs/apollo-server/performance/apq/),
+    /// the documents are stored in `storage` and expire after `ttl`, `None` means they never expire.
+    ///
+    /// Only queries and mutations can be requested by hash, subscriptions must send the full
+    /// document because the subscription transports do not support the `persistedQuery` extension.
+    pub fn automatic_persisted_queries<S: DocumentStorage>(
+        mut self,
+        storage: S,
+        ttl: Option<Duration>,
+    ) -> Self {
+        self.persisted_documents = Some(PersistedDocuments {
+            storage: Arc::new(storage),
+            only: false,
+            ttl,
+        });
+        self
+    }
+
+    /// Only allow executing the documents in `storage`, which is also known as an allow-list.
+    ///
+    /// A document can be requested by its SHA-256 hash, or by its source, as long as it is in the storage.
+    pub fn persisted_documents_only<S: DocumentStorage>(mut self, storage: S) -> Self {
+        self.persisted_documents = Some(PersistedDocuments {
+            storage: Arc::new(storage),
+            only: true,
+            ttl: None,
+        });
+        self
+    }
+
+    /// Rename the keys of the response data according to `key_casing`, e.g. `KeyCasing::SnakeCase`
+    /// converts `userName` to `user_name`. The `path` of errors uses the renamed keys as well.
+    ///
+    /// Aliases and the results of introspection fields are not renamed. A query in which an alias
+    /// is the same as the renamed key of a different field fails validation, e.g.
+    /// `{ userName user_name: firstName }`.
+    ///
+    /// `finish` will panic if two fields of a type are renamed to the same key.
+    pub fn response_key_casing(mut self, key_casing: KeyCasing) -> Self {
+        self.key_casing = Some(key_casing);
+        self
+    }
+
     /// Build schema.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    ///
+    /// - federation is enabled but the entity definitions are invalid,
+    /// - entities are defined but federation is not enabled,
+    /// - service health is enabled but the query root already has a `_health` field,
+    /// - a union or interface has no possible types.
     pub fn finish(mut self) -> Schema<Query, Mutation, Subscription> {
+        // health
+        if self.query.health.is_some() {
+            if let Err(err) = ServiceHealth::register(&mut self.registry) {
+                panic!("Failed to enable service health: {}", err);
+            }
+        }
+
         // federation
-        if self.enable_federation || self.registry.has_entities() {
+        if self.enable_federation {
+            if let Err(err) = self.registry.check_federation() {
+                panic!("Failed to enable federation: {}", err);
+            }
             self.registry.create_federation_types();
+        } else if self.registry.has_entities() {
+            panic!("Failed to build schema: entities are defined with `#[entity]`, but federation is not enabled. Call `.enable_federation()` on the schema builder.");
+        }
+
+        if let Err(err) = self.registry.check_possible_types() {
+            panic!("Invalid schema: {}", err);
+        }
+
+        // key casing
+        if let Some(key_casing) = self.key_casing {
+            if let Err(err) = self.registry.apply_key_casing(key_casing) {
+                panic!("Failed to apply the response key casing: {}", err);
+            }
         }
 
         Schema(Arc::new(SchemaInner {
@@ -106,6 +218,7 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
             complexity: self.complexity,
             depth: self.depth,
             extensions: self.extensions,
+            persisted_documents: self.persisted_documents,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
                 data: self.data,
@@ -141,6 +254,7 @@ pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
     pub(crate) extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    pub(crate) persisted_documents: Option<PersistedDocuments>,
     pub(crate) env: SchemaEnv,
 }
 
@@ -186,6 +300,7 @@ where
             types: Default::default(),
             directives: Default::default(),
             implements: Default::default(),
+            response_keys: Default::default(),
             query_type: Query::type_name().to_string(),
             mutation_type: if Mutation::is_empty() {
                 None
@@ -261,6 +376,7 @@ where
             query: QueryRoot {
                 inner: query,
                 disable_introspection: false,
+                health: None,
             },
             mutation,
             subscription,
@@ -270,6 +386,8 @@ where
             depth: None,
             extensions: Default::default(),
             enable_federation: false,
+            persisted_documents: None,
+            key_casing: None,
         }
     }
 
@@ -287,6 +405,24 @@ where
         QueryBuilder::new(query_source).execute(self).await
     }
 
+    pub(crate) async fn resolve_query_source(
+        &self,
+        query_source: String,
+        persisted_query_hash: Option<&str>,
+    ) -> Result<String> {
+        match &self.persisted_documents {
+            Some(persisted_documents) => {
+                persisted_documents
+                    .resolve(query_source, persisted_query_hash)
+                    .await
+            }
+            None if persisted_query_hash.is_some() && query_source.is_empty() => {
+                Err(QueryError::PersistedQueryNotSupported.into_error(Pos::default()))
+            }
+            None => Ok(query_source),
+        }
+    }
+
     pub(crate) fn prepare_query(
         &self,
         source: &str,
@@ -349,7 +485,8 @@ where
         variables: Variables,
         ctx_data: Option<Arc<Data>>,
     ) -> Result<impl Stream<Item = Result<serde_json::Value>> + Send> {
-        let (mut document, _, extensions) = self.prepare_query(source, &variables, &Vec::new())?;
+        let source = self.resolve_query_source(source.to_string(), None).await?;
+        let (mut document, _, extensions) = self.prepare_query(&source, &variables, &Vec::new())?;
 
         if !document.retain_operation(operation_name) {
             return if let Some(name) = operation_name {
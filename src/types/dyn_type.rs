@@ -0,0 +1,291 @@
+use crate::{ContextSelectionSet, Error, QueryError, Result};
+
+/// Check that `object` is a registered possible type of the union or interface `abstract_type`.
+#[doc(hidden)]
+pub fn check_possible_type(
+    ctx: &ContextSelectionSet<'_>,
+    abstract_type: &str,
+    object: &str,
+) -> Result<()> {
+    let registered = ctx
+        .schema_env
+        .registry
+        .types
+        .get(abstract_type)
+        .map(|ty| ty.is_possible_type(object))
+        .unwrap_or_default();
+    if registered {
+        Ok(())
+    } else {
+        Err(Error::Query {
+            pos: ctx.position(),
+            path: ctx
+                .path_node
+                .as_ref()
+                .and_then(|path| serde_json::to_value(path).ok()),
+            err: QueryError::UnregisteredPossibleType {
+                abstract_type: abstract_type.to_string(),
+                object: object.to_string(),
+            },
+        })
+    }
+}
+
+/// Define a GraphQL union with a trait object
+///
+/// Unlike `Union`, the possible types are not listed in an enum, the concrete type is determined at
+/// runtime by [`DynObjectType::resolve_type`](trait.DynObjectType.html#tymethod.resolve_type).
+/// The possible types need to be registered with `SchemaBuilder::register_possible_type`, resolving
+/// an unregistered type returns an error.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// trait Shape: DynObjectType {}
+///
+/// #[SimpleObject]
+/// struct Circle {
+///     radius: f32,
+/// }
+///
+/// impl Shape for Circle {}
+///
+/// #[SimpleObject]
+/// struct Square {
+///     width: f32,
+/// }
+///
+/// impl Shape for Square {}
+///
+/// dyn_union!(Box<dyn Shape>, "Shape", "A shape");
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn shapes(&self) -> Vec<Box<dyn Shape>> {
+///         vec![Box::new(Circle { radius: 10.0 }), Box::new(Square { width: 20.0 })]
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .register_possible_type::<Box<dyn Shape>, Circle>()
+///         .register_possible_type::<Box<dyn Shape>, Square>()
+///         .finish();
+///     let res = schema.execute(r#"
+///     {
+///         shapes {
+///             __typename
+///             ... on Circle { radius }
+///             ... on Square { width }
+///         }
+///     }"#).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({
+///         "shapes": [
+///             { "__typename": "Circle", "radius": 10.0 },
+///             { "__typename": "Square", "width": 20.0 },
+///         ]
+///     }));
+/// }
+/// ```
+#[macro_export]
+macro_rules! dyn_union {
+    (@impl $ty:ty, $name:expr, $desc:expr) => {
+        #[allow(clippy::all, clippy::pedantic)]
+        impl $crate::Type for $ty {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed($name)
+            }
+
+            fn introspection_type_name(&self) -> ::std::borrow::Cow<'static, str> {
+                $crate::DynObjectType::resolve_type(&**self)
+            }
+
+            fn create_type_info(registry: &mut $crate::registry::Registry) -> String {
+                registry.create_type::<Self, _>(|_| $crate::registry::MetaType::Union {
+                    name: $name.to_string(),
+                    description: $desc,
+                    possible_types: Default::default(),
+                })
+            }
+        }
+
+        #[allow(clippy::all, clippy::pedantic)]
+        #[$crate::async_trait::async_trait]
+        impl $crate::ObjectType for $ty {
+            async fn resolve_field(
+                &self,
+                ctx: &$crate::Context<'_>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                Err($crate::QueryError::FieldNotFound {
+                    field_name: ctx.name.to_string(),
+                    object: $name.to_string(),
+                }
+                .into_error(ctx.position()))
+            }
+
+            fn collect_inline_fields<'a>(
+                &'a self,
+                name: &str,
+                ctx: &$crate::ContextSelectionSet<'a>,
+                futures: &mut Vec<$crate::BoxFieldFuture<'a>>,
+            ) -> $crate::Result<()> {
+                $crate::DynObjectType::dyn_collect_inline_fields(&**self, name, ctx, futures)
+            }
+        }
+
+        #[allow(clippy::all, clippy::pedantic)]
+        #[$crate::async_trait::async_trait]
+        impl $crate::OutputValueType for $ty {
+            async fn resolve(
+                &self,
+                ctx: &$crate::ContextSelectionSet<'_>,
+                _field: &$crate::Positioned<$crate::parser::query::Field>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                $crate::check_possible_type(
+                    ctx,
+                    &<Self as $crate::Type>::type_name(),
+                    &$crate::DynObjectType::resolve_type(&**self),
+                )?;
+                $crate::do_resolve(ctx, self).await
+            }
+        }
+    };
+    ($ty:ty, $name:expr) => {
+        $crate::dyn_union!(@impl $ty, $name, None);
+    };
+    ($ty:ty, $name:expr, $desc:expr) => {
+        $crate::dyn_union!(@impl $ty, $name, Some($desc));
+    };
+}
+
+/// Define a GraphQL interface with a trait object
+///
+/// The fields of the interface are defined by an existing interface type (usually defined with
+/// `Interface`), and the concrete type is determined at runtime by
+/// [`DynObjectType::resolve_type`](trait.DynObjectType.html#tymethod.resolve_type).
+/// Additional implementations need to be registered with `SchemaBuilder::register_possible_type`,
+/// resolving an unregistered type returns an error.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// trait Node: DynObjectType {}
+///
+/// #[SimpleObject]
+/// struct User {
+///     id: ID,
+///     name: String,
+/// }
+///
+/// impl Node for User {}
+///
+/// #[SimpleObject]
+/// struct Post {
+///     id: ID,
+///     title: String,
+/// }
+///
+/// impl Node for Post {}
+///
+/// #[Interface(field(name = "id", type = "&ID"))]
+/// enum NodeInterface {
+///     User(User),
+/// }
+///
+/// dyn_interface!(Box<dyn Node>, NodeInterface);
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn nodes(&self) -> Vec<Box<dyn Node>> {
+///         vec![
+///             Box::new(User { id: "1".into(), name: "sunli".to_string() }),
+///             Box::new(Post { id: "2".into(), title: "hello".to_string() }),
+///         ]
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .register_possible_type::<Box<dyn Node>, Post>()
+///         .finish();
+///     let res = schema.execute(r#"
+///     {
+///         nodes {
+///             id
+///             ... on Post { title }
+///         }
+///     }"#).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({
+///         "nodes": [
+///             { "id": "1" },
+///             { "id": "2", "title": "hello" },
+///         ]
+///     }));
+/// }
+/// ```
+#[macro_export]
+macro_rules! dyn_interface {
+    ($ty:ty, $interface:ty) => {
+        #[allow(clippy::all, clippy::pedantic)]
+        impl $crate::Type for $ty {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                <$interface as $crate::Type>::type_name()
+            }
+
+            fn introspection_type_name(&self) -> ::std::borrow::Cow<'static, str> {
+                $crate::DynObjectType::resolve_type(&**self)
+            }
+
+            fn create_type_info(registry: &mut $crate::registry::Registry) -> String {
+                <$interface as $crate::Type>::create_type_info(registry)
+            }
+        }
+
+        #[allow(clippy::all, clippy::pedantic)]
+        #[$crate::async_trait::async_trait]
+        impl $crate::ObjectType for $ty {
+            async fn resolve_field(
+                &self,
+                ctx: &$crate::Context<'_>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                $crate::DynObjectType::dyn_resolve_field(&**self, ctx).await
+            }
+
+            fn collect_inline_fields<'a>(
+                &'a self,
+                name: &str,
+                ctx: &$crate::ContextSelectionSet<'a>,
+                futures: &mut Vec<$crate::BoxFieldFuture<'a>>,
+            ) -> $crate::Result<()> {
+                $crate::DynObjectType::dyn_collect_inline_fields(&**self, name, ctx, futures)
+            }
+        }
+
+        #[allow(clippy::all, clippy::pedantic)]
+        #[$crate::async_trait::async_trait]
+        impl $crate::OutputValueType for $ty {
+            async fn resolve(
+                &self,
+                ctx: &$crate::ContextSelectionSet<'_>,
+                _field: &$crate::Positioned<$crate::parser::query::Field>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                $crate::check_possible_type(
+                    ctx,
+                    &<Self as $crate::Type>::type_name(),
+                    &$crate::DynObjectType::resolve_type(&**self),
+                )?;
+                $crate::do_resolve(ctx, self).await
+            }
+        }
+    };
+}
@@ -1,5 +1,6 @@
 pub mod connection;
 
+mod dyn_type;
 mod empty_mutation;
 mod empty_subscription;
 mod r#enum;
@@ -9,6 +10,7 @@ mod optional;
 mod query_root;
 mod upload;
 
+pub use dyn_type::check_possible_type;
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
 pub use maybe_undefined::MaybeUndefined;
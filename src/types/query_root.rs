@@ -1,3 +1,4 @@
+use crate::health::ServiceHealth;
 use crate::model::{__Schema, __Type};
 use crate::scalars::Any;
 use crate::{
@@ -18,6 +19,7 @@ struct Service {
 pub struct QueryRoot<T> {
     pub inner: T,
     pub disable_introspection: bool,
+    pub health: Option<ServiceHealth>,
 }
 
 impl<T: Type> Type for QueryRoot<T> {
@@ -125,6 +127,11 @@ impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
                 res.push(self.inner.find_entity(ctx, &item.0).await?);
             }
             return Ok(res.into());
+        } else if ctx.name.node == "_health" {
+            if let Some(health) = &self.health {
+                let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+                return OutputValueType::resolve(&health.health().await, &ctx_obj, ctx.item).await;
+            }
         } else if ctx.name.node == "_service" {
             let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
             return OutputValueType::resolve(
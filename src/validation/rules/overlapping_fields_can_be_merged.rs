@@ -27,14 +27,19 @@ struct FindConflicts<'a, 'ctx> {
 
 impl<'a, 'ctx> FindConflicts<'a, 'ctx> {
     pub fn find(&mut self, selection_set: &'a Positioned<SelectionSet>) {
+        let registry = self.ctx.registry;
+        let in_introspection = self
+            .ctx
+            .current_type()
+            .map(|ty| ty.name().starts_with("__"))
+            .unwrap_or_default();
         for selection in &selection_set.items {
             match &selection.node {
                 Selection::Field(field) => {
-                    let output_name = field
-                        .alias
-                        .as_ref()
-                        .map(|name| name.as_str())
-                        .unwrap_or_else(|| field.name.as_str());
+                    let output_name = match &field.alias {
+                        Some(alias) => alias.as_str(),
+                        None => registry.response_key(field.name.as_str(), in_introspection),
+                    };
                     self.add_output(&output_name, field);
                 }
                 Selection::InlineFragment(inline_fragment) => {
@@ -98,21 +98,12 @@ pub fn is_valid_input_value(
             if let Some(ty) = registry.types.get(type_name) {
                 match ty {
                     registry::MetaType::Scalar { is_valid, .. } => {
-                        let value = match value {
-                            Value::Variable(var_name) => {
-                                variables.and_then(|variables| variables.get(var_name))
-                            }
-                            _ => Some(value),
-                        };
-                        if let Some(value) = value {
-                            if !is_valid(value) {
-                                Some(valid_error(
-                                    &path_node,
-                                    format!("expected type \"{}\"", type_name),
-                                ))
-                            } else {
-                                None
-                            }
+                        // Literals that reference variables can only be checked during execution
+                        if referenced_variables(value).is_empty() && !is_valid(value) {
+                            Some(valid_error(
+                                &path_node,
+                                format!("expected type \"{}\"", type_name),
+                            ))
                         } else {
                             None
                         }
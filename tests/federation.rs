@@ -69,7 +69,9 @@ impl QueryRoot {
 
 #[async_std::test]
 pub async fn test_federation() {
-    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
     let query = r#"{
             _entities(representations: [{__typename: "Product", upc: "B00005N5PF"}]) {
                 __typename
@@ -87,3 +89,63 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_federation_disabled() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert!(schema.execute("{ _service { sdl } }").await.is_err());
+    assert!(schema
+        .execute(r#"{ _entities(representations: [{__typename: "Product", upc: "B00005N5PF"}]) { __typename } }"#)
+        .await
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Call `.enable_federation()`")]
+pub fn test_federation_entities_without_federation() {
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(expected = "no entity is defined")]
+pub fn test_federation_without_entities() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+}
+
+#[test]
+#[should_panic(expected = "field \"sku\" does not exist")]
+pub fn test_federation_invalid_key() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[entity]
+        async fn find_product_by_sku(&self, sku: String) -> Product {
+            Product { upc: sku }
+        }
+    }
+
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+}
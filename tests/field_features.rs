@@ -184,3 +184,88 @@ pub async fn test_field_features() {
         unreachable!()
     }
 }
+
+#[async_std::test]
+pub async fn test_field_features_custom_error() {
+    #[derive(Debug)]
+    struct AppError;
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Not found")
+        }
+    }
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        #[field(feature = "abc")]
+        async fn values_abc(&self) -> Result<impl Stream<Item = i32>, AppError> {
+            Ok(futures::stream::once(async move { 10 }))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field(feature = "bson")]
+        async fn value_bson(&self) -> Result<i32, AppError> {
+            Ok(10)
+        }
+
+        #[field(feature = "abc")]
+        async fn value_abc(&self) -> Result<i32, AppError> {
+            Err(AppError)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, Subscription);
+    assert_eq!(
+        schema.execute("{ valueBson }").await.unwrap().data,
+        serde_json::json!({
+            "valueBson": 10,
+        })
+    );
+
+    assert_eq!(
+        schema.execute("{ valueAbc }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { column: 3, line: 1 },
+            path: Some(serde_json::json!(["valueAbc"])),
+            err: QueryError::FieldError {
+                err: "`valueAbc` is only available if the features `abc` are enabled".to_string(),
+                extended_error: None
+            }
+        }
+    );
+
+    let res = schema
+        .create_subscription_stream(
+            "subscription { valuesAbc }",
+            None,
+            Default::default(),
+            Default::default(),
+        )
+        .await;
+    if let Err(err) = res {
+        assert_eq!(
+            err,
+            Error::Query {
+                pos: Pos {
+                    column: 16,
+                    line: 1
+                },
+                path: Some(serde_json::json!(["valuesAbc"])),
+                err: QueryError::FieldError {
+                    err: "`valuesAbc` is only available if the features `abc` are enabled"
+                        .to_string(),
+                    extended_error: None
+                }
+            }
+        )
+    } else {
+        unreachable!()
+    }
+}
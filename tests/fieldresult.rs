@@ -57,3 +57,173 @@ pub async fn test_fieldresult() {
         }
     );
 }
+
+#[async_std::test]
+pub async fn test_custom_error() {
+    #[derive(Debug)]
+    enum AppError {
+        NotFound,
+    }
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                AppError::NotFound => write!(f, "Not found"),
+            }
+        }
+    }
+
+    impl AppError {
+        fn into_graphql(self) -> FieldError {
+            match self {
+                AppError::NotFound => FieldError(
+                    "NotFound".to_string(),
+                    Some(serde_json::json!({"code": 404})),
+                ),
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn error(&self) -> Result<i32, AppError> {
+            Err(AppError::NotFound)
+        }
+
+        async fn result_error(&self) -> FieldResult<i32, AppError> {
+            Err(AppError::NotFound)
+        }
+
+        #[field(map_err = "AppError::into_graphql")]
+        async fn map_error(&self) -> Result<i32, AppError> {
+            Err(AppError::NotFound)
+        }
+
+        async fn value(&self) -> Result<i32, AppError> {
+            Ok(10)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema.execute("{ value }").await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    assert_eq!(
+        schema.execute("{ error }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["error"])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema.execute("{ resultError }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["resultError"])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema.execute("{ mapError }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["mapError"])),
+            err: QueryError::FieldError {
+                err: "NotFound".to_string(),
+                extended_error: Some(serde_json::json!({"code": 404})),
+            },
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_custom_error_interface() {
+    #[derive(Debug)]
+    struct AppError;
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Not found")
+        }
+    }
+
+    impl AppError {
+        fn into_graphql(self) -> FieldError {
+            FieldError(
+                "NotFound".to_string(),
+                Some(serde_json::json!({"code": 404})),
+            )
+        }
+    }
+
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn error(&self) -> Result<i32, AppError> {
+            Err(AppError)
+        }
+
+        #[field(map_err = "AppError::into_graphql")]
+        async fn map_error(&self) -> Result<i32, AppError> {
+            Err(AppError)
+        }
+    }
+
+    #[Interface(
+        field(name = "error", type = "i32"),
+        field(name = "map_error", type = "i32")
+    )]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema.execute("{ obj { error } }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 9 },
+            path: Some(serde_json::json!(["obj", "error"])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema.execute("{ obj { mapError } }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 9 },
+            path: Some(serde_json::json!(["obj", "mapError"])),
+            err: QueryError::FieldError {
+                err: "NotFound".to_string(),
+                extended_error: Some(serde_json::json!({"code": 404})),
+            },
+        }
+    );
+}
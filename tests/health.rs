@@ -0,0 +1,140 @@
+use async_graphql::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[async_std::test]
+pub async fn test_health() {
+    let broker_connected = Arc::new(AtomicBool::new(false));
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .service_health(
+            ServiceHealth::new()
+                .version("1.2.3")
+                .readiness_check("database", || async { Ok(()) })
+                .readiness_check("broker", {
+                    let broker_connected = broker_connected.clone();
+                    move || {
+                        let connected = broker_connected.load(Ordering::SeqCst);
+                        async move {
+                            if connected {
+                                Ok(())
+                            } else {
+                                Err("not connected".into())
+                            }
+                        }
+                    }
+                }),
+        )
+        .finish();
+
+    let query = "{ _health { status version checks { name ready error } } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "_health": {
+                "status": "DOWN",
+                "version": "1.2.3",
+                "checks": [
+                    { "name": "database", "ready": true, "error": null },
+                    { "name": "broker", "ready": false, "error": "not connected" },
+                ]
+            }
+        })
+    );
+
+    let data = schema.execute("{ _health { uptime } }").await.unwrap().data;
+    assert!(data["_health"]["uptime"].as_u64().unwrap() < 5);
+
+    broker_connected.store(true, Ordering::SeqCst);
+    assert_eq!(
+        schema.execute("{ _health { status } }").await.unwrap().data,
+        serde_json::json!({
+            "_health": { "status": "UP" }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_health_disabled() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert!(schema.execute("{ _health { status } }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_health_timeout() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .service_health(
+            ServiceHealth::new()
+                .timeout(Duration::from_millis(50))
+                .readiness_check("database", || async { Ok(()) })
+                .readiness_check("broker", || futures::future::pending::<FieldResult<()>>()),
+        )
+        .finish();
+
+    let query = "{ _health { status checks { name ready error } } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "_health": {
+                "status": "DOWN",
+                "checks": [
+                    { "name": "database", "ready": true, "error": null },
+                    { "name": "broker", "ready": false, "error": "timeout" },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_health_hide_errors() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .service_health(
+            ServiceHealth::new()
+                .hide_errors()
+                .readiness_check("database", || async {
+                    Err("postgres://admin:secret@db:5432 refused".into())
+                }),
+        )
+        .finish();
+
+    let query = "{ _health { status checks { name ready error } } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "_health": {
+                "status": "DOWN",
+                "checks": [
+                    { "name": "database", "ready": false, "error": null },
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Failed to enable service health")]
+pub fn test_health_conflict() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[field(name = "_health")]
+        async fn health(&self) -> i32 {
+            10
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .service_health(ServiceHealth::new())
+        .finish();
+}
@@ -27,3 +27,117 @@ pub async fn test_input_value_custom_error() {
         }
     );
 }
+
+#[async_std::test]
+pub async fn test_input_value_invalid_scalar_literal() {
+    struct MyScalar(String);
+
+    #[Scalar]
+    impl ScalarType for MyScalar {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) if s.starts_with("my:") => Ok(MyScalar(s)),
+                _ => Err(InputValueError::ExpectedType(value)),
+            }
+        }
+
+        fn is_valid(value: &Value) -> bool {
+            match value {
+                Value::String(s) => s.starts_with("my:"),
+                _ => false,
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct OtherScalar(String);
+
+    #[Scalar]
+    impl ScalarType for OtherScalar {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) if s.starts_with("other:") => Ok(OtherScalar(s)),
+                _ => Err(InputValueError::Custom(
+                    "Must start with \"other:\"".to_string(),
+                )),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn date(&self, _d: chrono::DateTime<chrono::Utc>) -> bool {
+            true
+        }
+
+        async fn custom(&self, _s: MyScalar) -> bool {
+            true
+        }
+
+        async fn other(&self, _s: OtherScalar) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{ date(d: "2020-06-01T10:00:00Z") custom(s: "my:abc") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "date": true, "custom": true })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ date(d: "2020-13-01") }"#)
+            .await
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 8 }],
+                message: r#"Invalid value for argument "d", expected type "DateTimeUtc""#
+                    .to_string(),
+            }]
+        }
+    );
+
+    assert_eq!(
+        schema.execute(r#"{ custom(s: "abc") }"#).await.unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos {
+                    line: 1,
+                    column: 10
+                }],
+                message: r#"Invalid value for argument "s", expected type "MyScalar""#.to_string(),
+            }]
+        }
+    );
+
+    // Scalars that do not implement `is_valid` report the error of `parse` during execution
+    assert_eq!(
+        schema.execute(r#"{ other(s: "abc") }"#).await.unwrap_err(),
+        Error::Query {
+            pos: Pos {
+                line: 1,
+                column: 12
+            },
+            path: None,
+            err: QueryError::ParseInputValue {
+                reason: r#"Must start with "other:""#.to_string()
+            },
+        }
+    );
+}
@@ -323,3 +323,125 @@ pub async fn test_interface_field_method() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_dyn_interface() {
+    trait Node: DynObjectType {}
+
+    #[SimpleObject]
+    struct User {
+        id: i32,
+        name: String,
+    }
+
+    impl Node for User {}
+
+    #[SimpleObject]
+    struct Post {
+        id: i32,
+        title: String,
+    }
+
+    impl Node for Post {}
+
+    #[Interface(field(name = "id", type = "&i32"))]
+    enum NodeInterface {
+        User(User),
+    }
+
+    dyn_interface!(Box<dyn Node>, NodeInterface);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn nodes(&self) -> Vec<Box<dyn Node>> {
+            vec![
+                Box::new(User {
+                    id: 1,
+                    name: "sunli".to_string(),
+                }),
+                Box::new(Post {
+                    id: 2,
+                    title: "hello".to_string(),
+                }),
+            ]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_possible_type::<Box<dyn Node>, Post>()
+        .finish();
+
+    let query = r#"{
+            nodes {
+                __typename
+                id
+                ... on User {
+                    name
+                }
+                ... on Post {
+                    title
+                }
+            }
+            __type(name: "NodeInterface") {
+                kind
+                possibleTypes { name }
+            }
+        }"#;
+    assert_eq!(
+        schema.execute(&query).await.unwrap().data,
+        serde_json::json!({
+            "nodes": [
+                { "__typename": "User", "id": 1, "name": "sunli" },
+                { "__typename": "Post", "id": 2, "title": "hello" },
+            ],
+            "__type": {
+                "kind": "INTERFACE",
+                "possibleTypes": [{ "name": "User" }, { "name": "Post" }],
+            }
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Object \"Post\" does not have the field \"id\" of interface \"NodeInterface\""
+)]
+pub fn test_dyn_interface_missing_field() {
+    trait Node: DynObjectType {}
+
+    #[SimpleObject]
+    struct User {
+        id: i32,
+    }
+
+    impl Node for User {}
+
+    #[SimpleObject]
+    struct Post {
+        title: String,
+    }
+
+    impl Node for Post {}
+
+    #[Interface(field(name = "id", type = "&i32"))]
+    enum NodeInterface {
+        User(User),
+    }
+
+    dyn_interface!(Box<dyn Node>, NodeInterface);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn nodes(&self) -> Vec<Box<dyn Node>> {
+            Vec::new()
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_possible_type::<Box<dyn Node>, Post>()
+        .finish();
+}
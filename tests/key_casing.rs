@@ -0,0 +1,176 @@
+use async_graphql::*;
+
+#[SimpleObject]
+struct UserProfile {
+    first_name: String,
+    last_name: String,
+}
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn user_profile(&self) -> UserProfile {
+        UserProfile {
+            first_name: "Sun".to_string(),
+            last_name: "Li".to_string(),
+        }
+    }
+
+    async fn user_list(&self) -> Vec<FieldResult<i32>> {
+        vec![Ok(1), Err("Not found".into())]
+    }
+}
+
+struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn update_profile(&self, first_name: String) -> UserProfile {
+        UserProfile {
+            first_name,
+            last_name: "Li".to_string(),
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_key_casing() {
+    let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .response_key_casing(KeyCasing::SnakeCase)
+        .finish();
+
+    let query = r#"{
+        userProfile { __typename firstName lastName }
+        profile: userProfile { givenName: firstName lastName }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "user_profile": {
+                "__typename": "UserProfile",
+                "first_name": "Sun",
+                "last_name": "Li",
+            },
+            "profile": {
+                "givenName": "Sun",
+                "last_name": "Li",
+            }
+        })
+    );
+
+    let query = r#"mutation { updateProfile(firstName: "Tian") { firstName } }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "update_profile": {
+                "first_name": "Tian",
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_key_casing_introspection() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .response_key_casing(KeyCasing::SnakeCase)
+        .finish();
+
+    let query = r#"{
+        __type(name: "UserProfile") {
+            name
+            fields { name }
+            possibleTypes { name }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "__type": {
+                "name": "UserProfile",
+                "fields": [
+                    { "name": "firstName" },
+                    { "name": "lastName" },
+                ],
+                "possibleTypes": null,
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_key_casing_error_path() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .response_key_casing(KeyCasing::SnakeCase)
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ userList }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["user_list", 1])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+
+    assert_eq!(
+        schema.execute("{ users: userList }").await.unwrap_err(),
+        Error::Query {
+            pos: Pos { line: 1, column: 3 },
+            path: Some(serde_json::json!(["users", 1])),
+            err: QueryError::FieldError {
+                err: "Not found".to_string(),
+                extended_error: None,
+            },
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "have the same response key \"user_name\"")]
+pub fn test_key_casing_conflict() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user_name(&self) -> i32 {
+            1
+        }
+
+        #[field(name = "user_name")]
+        async fn user_name2(&self) -> i32 {
+            2
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .response_key_casing(KeyCasing::SnakeCase)
+        .finish();
+}
+
+#[async_std::test]
+pub async fn test_key_casing_alias_conflict() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .response_key_casing(KeyCasing::SnakeCase)
+        .finish();
+
+    let query = "{ userProfile { firstName last_name: firstName } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "user_profile": {
+                "first_name": "Sun",
+                "last_name": "Sun",
+            }
+        })
+    );
+
+    let query = "{ userProfile { lastName last_name: firstName } }";
+    assert!(schema.execute(query).await.is_err());
+
+    let query = "{ userProfile { first_name: lastName firstName } }";
+    assert!(schema.execute(query).await.is_err());
+}
@@ -0,0 +1,206 @@
+use async_graphql::persisted_documents::{document_hash, DocumentStorage, MemoryStorage};
+use async_graphql::*;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[async_std::test]
+pub async fn test_automatic_persisted_queries() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .automatic_persisted_queries(MemoryStorage::default(), None)
+        .finish();
+    let query = "{ value }";
+    let hash = document_hash(query);
+
+    assert_eq!(
+        QueryBuilder::new("")
+            .persisted_query_hash(&hash)
+            .execute(&schema)
+            .await
+            .unwrap_err(),
+        QueryError::PersistedQueryNotFound.into_error(Pos::default())
+    );
+
+    assert_eq!(
+        QueryBuilder::new(query)
+            .persisted_query_hash("abc")
+            .execute(&schema)
+            .await
+            .unwrap_err(),
+        QueryError::PersistedQueryHashMismatch.into_error(Pos::default())
+    );
+
+    assert_eq!(
+        QueryBuilder::new(query)
+            .persisted_query_hash(&hash)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    assert_eq!(
+        QueryBuilder::new("")
+            .persisted_query_hash(&hash)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_persisted_documents_only() {
+    let query = "{ value }";
+    let hash = document_hash(query);
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .persisted_documents_only(MemoryStorage::with_documents(vec![query]))
+        .finish();
+
+    assert_eq!(
+        QueryBuilder::new("")
+            .persisted_query_hash(&hash)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    assert_eq!(
+        schema.execute("{ a: value }").await.unwrap_err(),
+        QueryError::PersistedDocumentsOnly.into_error(Pos::default())
+    );
+}
+
+#[async_std::test]
+pub async fn test_persisted_query_not_supported() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        QueryBuilder::new("")
+            .persisted_query_hash("abc")
+            .execute(&schema)
+            .await
+            .unwrap_err(),
+        QueryError::PersistedQueryNotSupported.into_error(Pos::default())
+    );
+}
+
+#[async_std::test]
+pub async fn test_memory_storage_capacity() {
+    let storage = MemoryStorage::new(2);
+    storage.set("a", "{ a }", None).await.unwrap();
+    storage.set("b", "{ b }", None).await.unwrap();
+    assert_eq!(storage.get("a").await.unwrap().as_deref(), Some("{ a }"));
+
+    // "b" is the least recently used document
+    storage.set("c", "{ c }", None).await.unwrap();
+    assert_eq!(storage.get("b").await.unwrap(), None);
+    assert_eq!(storage.get("a").await.unwrap().as_deref(), Some("{ a }"));
+    assert_eq!(storage.get("c").await.unwrap().as_deref(), Some("{ c }"));
+
+    // Expired documents are removed first
+    storage
+        .set("d", "{ d }", Some(std::time::Duration::from_secs(0)))
+        .await
+        .unwrap();
+    storage.set("e", "{ e }", None).await.unwrap();
+    assert_eq!(storage.get("d").await.unwrap(), None);
+    assert_eq!(storage.get("e").await.unwrap().as_deref(), Some("{ e }"));
+}
+
+#[cfg(feature = "fs-storage")]
+#[async_std::test]
+pub async fn test_fs_storage() {
+    use async_graphql::persisted_documents::FsStorage;
+
+    let query = "{ value }";
+    let hash = document_hash(query);
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join(format!("{}.graphql", hash)), query).unwrap();
+    std::fs::write(dir.path().join("README.md"), "documents").unwrap();
+    std::fs::write(dir.path().join("query.graphql"), "{ a: value }").unwrap();
+
+    let storage = FsStorage::new(dir.path()).unwrap();
+    assert_eq!(storage.get(&hash).await.unwrap().as_deref(), Some(query));
+    assert_eq!(storage.get("query").await.unwrap(), None);
+    assert!(storage.set(&hash, query, None).await.is_err());
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .persisted_documents_only(storage)
+        .finish();
+    assert_eq!(
+        QueryBuilder::new("")
+            .persisted_query_hash(&hash)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    std::fs::write(
+        dir.path()
+            .join(format!("{}.graphql", document_hash("{ a }"))),
+        "{ b }",
+    )
+    .unwrap();
+    assert!(FsStorage::new(dir.path()).is_err());
+}
+
+#[async_std::test]
+pub async fn test_automatic_persisted_queries_storage_error() {
+    use async_graphql::persisted_documents::{DocumentStorageError, DocumentStorageResult};
+    use std::time::Duration;
+
+    struct BrokenStorage;
+
+    #[async_graphql::async_trait::async_trait]
+    impl DocumentStorage for BrokenStorage {
+        async fn get(&self, _hash: &str) -> DocumentStorageResult<Option<String>> {
+            Err(DocumentStorageError::Custom(
+                "connection refused".to_string(),
+            ))
+        }
+
+        async fn set(
+            &self,
+            _hash: &str,
+            _document: &str,
+            _ttl: Option<Duration>,
+        ) -> DocumentStorageResult<()> {
+            Err(DocumentStorageError::Custom(
+                "connection refused".to_string(),
+            ))
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .automatic_persisted_queries(BrokenStorage, None)
+        .finish();
+    let query = "{ value }";
+
+    // The full query is executed even if it cannot be stored
+    assert_eq!(
+        QueryBuilder::new(query)
+            .persisted_query_hash(&document_hash(query))
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "value": 10 })
+    );
+}
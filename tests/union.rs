@@ -284,3 +284,171 @@ pub async fn test_union_field_result() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_dyn_union() {
+    trait Shape: DynObjectType {}
+
+    #[SimpleObject]
+    struct Circle {
+        radius: i32,
+    }
+
+    impl Shape for Circle {}
+
+    #[SimpleObject]
+    struct Square {
+        width: i32,
+    }
+
+    impl Shape for Square {}
+
+    dyn_union!(Box<dyn Shape>, "Shape");
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn shapes(&self) -> Vec<Box<dyn Shape>> {
+            vec![
+                Box::new(Circle { radius: 10 }),
+                Box::new(Square { width: 20 }),
+            ]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_possible_type::<Box<dyn Shape>, Circle>()
+        .register_possible_type::<Box<dyn Shape>, Square>()
+        .finish();
+
+    let query = r#"{
+            shapes {
+                __typename
+                ... on Circle {
+                    radius
+                }
+                ... on Square {
+                    width
+                }
+            }
+            __type(name: "Shape") {
+                kind
+                possibleTypes { name }
+            }
+        }"#;
+    assert_eq!(
+        schema.execute(&query).await.unwrap().data,
+        serde_json::json!({
+            "shapes": [
+                { "__typename": "Circle", "radius": 10 },
+                { "__typename": "Square", "width": 20 },
+            ],
+            "__type": {
+                "kind": "UNION",
+                "possibleTypes": [{ "name": "Circle" }, { "name": "Square" }],
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_dyn_union_unregistered_type() {
+    trait Shape: DynObjectType {}
+
+    #[SimpleObject]
+    struct Circle {
+        radius: i32,
+    }
+
+    impl Shape for Circle {}
+
+    #[SimpleObject]
+    struct Square {
+        width: i32,
+    }
+
+    impl Shape for Square {}
+
+    dyn_union!(Box<dyn Shape>, "Shape");
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn shapes(&self) -> Vec<Box<dyn Shape>> {
+            vec![
+                Box::new(Circle { radius: 10 }),
+                Box::new(Square { width: 20 }),
+            ]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_possible_type::<Box<dyn Shape>, Circle>()
+        .finish();
+
+    match schema
+        .execute("{ shapes { __typename } }")
+        .await
+        .unwrap_err()
+    {
+        Error::Query { path, err, .. } => {
+            assert_eq!(path, Some(serde_json::json!(["shapes", 1])));
+            assert_eq!(
+                err,
+                QueryError::UnregisteredPossibleType {
+                    abstract_type: "Shape".to_string(),
+                    object: "Square".to_string(),
+                }
+            );
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+#[should_panic(expected = "Union \"Shape\" has no possible types")]
+pub fn test_dyn_union_without_possible_types() {
+    trait Shape: DynObjectType {}
+
+    dyn_union!(Box<dyn Shape>, "Shape");
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn shapes(&self) -> Vec<Box<dyn Shape>> {
+            Vec::new()
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription).finish();
+}
+
+#[test]
+#[should_panic(expected = "\"Circle\" is not a union or interface")]
+pub fn test_register_possible_type_not_abstract() {
+    #[SimpleObject]
+    struct Circle {
+        radius: i32,
+    }
+
+    #[SimpleObject]
+    struct Square {
+        width: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn circle(&self) -> Circle {
+            Circle { radius: 10 }
+        }
+    }
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_possible_type::<Circle, Square>()
+        .finish();
+}